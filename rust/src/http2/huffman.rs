@@ -0,0 +1,483 @@
+/* Copyright (C) 2020 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! HPACK Huffman decoding (RFC 7541, Appendix B).
+//!
+//! Decoding used to walk the canonical Huffman code one bit at a time via
+//! `nom`'s `bits!`/`many0!` combinators. That is correct but costly on
+//! header-heavy HTTP/2 flows, since every single bit of every compressed
+//! header goes through the combinator machinery. Instead we precompute,
+//! once, a finite-state machine that consumes a whole input byte per step:
+//! `HUFFMAN_FSM[state][byte]` gives the symbols emitted, the next state,
+//! and whether the transition is valid. The state space is just the set of
+//! nodes of the Huffman code trie, so it stays in the low hundreds.
+
+use nom::error::ErrorKind;
+use nom::{Err, IResult};
+use std::sync::OnceLock;
+
+/// (code, code length in bits) for each of the 256 byte values, plus the
+/// EOS pseudo-symbol (index 256), as specified by RFC 7541 Appendix B.
+static HUFFMAN_CODES: [(u32, u8); 257] = [
+    (0x00001ff8, 13),
+    (0x007fffd8, 23),
+    (0x0fffffe2, 28),
+    (0x0fffffe3, 28),
+    (0x0fffffe4, 28),
+    (0x0fffffe5, 28),
+    (0x0fffffe6, 28),
+    (0x0fffffe7, 28),
+    (0x0fffffe8, 28),
+    (0x00ffffea, 24),
+    (0x3ffffffc, 30),
+    (0x0fffffe9, 28),
+    (0x0fffffea, 28),
+    (0x3ffffffd, 30),
+    (0x0fffffeb, 28),
+    (0x0fffffec, 28),
+    (0x0fffffed, 28),
+    (0x0fffffee, 28),
+    (0x0fffffef, 28),
+    (0x0ffffff0, 28),
+    (0x0ffffff1, 28),
+    (0x0ffffff2, 28),
+    (0x3ffffffe, 30),
+    (0x0ffffff3, 28),
+    (0x0ffffff4, 28),
+    (0x0ffffff5, 28),
+    (0x0ffffff6, 28),
+    (0x0ffffff7, 28),
+    (0x0ffffff8, 28),
+    (0x0ffffff9, 28),
+    (0x0ffffffa, 28),
+    (0x0ffffffb, 28),
+    (0x00000014, 6),
+    (0x000003f8, 10),
+    (0x000003f9, 10),
+    (0x00000ffa, 12),
+    (0x00001ff9, 13),
+    (0x00000015, 6),
+    (0x000000f8, 8),
+    (0x000007fa, 11),
+    (0x000003fa, 10),
+    (0x000003fb, 10),
+    (0x000000f9, 8),
+    (0x000007fb, 11),
+    (0x000000fa, 8),
+    (0x00000016, 6),
+    (0x00000017, 6),
+    (0x00000018, 6),
+    (0x00000000, 5),
+    (0x00000001, 5),
+    (0x00000002, 5),
+    (0x00000019, 6),
+    (0x0000001a, 6),
+    (0x0000001b, 6),
+    (0x0000001c, 6),
+    (0x0000001d, 6),
+    (0x0000001e, 6),
+    (0x0000001f, 6),
+    (0x0000005c, 7),
+    (0x000000fb, 8),
+    (0x00007ffc, 15),
+    (0x00000020, 6),
+    (0x00000ffb, 12),
+    (0x000003fc, 10),
+    (0x00001ffa, 13),
+    (0x00000021, 6),
+    (0x0000005d, 7),
+    (0x0000005e, 7),
+    (0x0000005f, 7),
+    (0x00000060, 7),
+    (0x00000061, 7),
+    (0x00000062, 7),
+    (0x00000063, 7),
+    (0x00000064, 7),
+    (0x00000065, 7),
+    (0x00000066, 7),
+    (0x00000067, 7),
+    (0x00000068, 7),
+    (0x00000069, 7),
+    (0x0000006a, 7),
+    (0x0000006b, 7),
+    (0x0000006c, 7),
+    (0x0000006d, 7),
+    (0x0000006e, 7),
+    (0x0000006f, 7),
+    (0x00000070, 7),
+    (0x00000071, 7),
+    (0x00000072, 7),
+    (0x000000fc, 8),
+    (0x00000073, 7),
+    (0x000000fd, 8),
+    (0x00001ffb, 13),
+    (0x0007fff0, 19),
+    (0x00001ffc, 13),
+    (0x00003ffc, 14),
+    (0x00000022, 6),
+    (0x00007ffd, 15),
+    (0x00000003, 5),
+    (0x00000023, 6),
+    (0x00000004, 5),
+    (0x00000024, 6),
+    (0x00000005, 5),
+    (0x00000025, 6),
+    (0x00000026, 6),
+    (0x00000027, 6),
+    (0x00000006, 5),
+    (0x00000074, 7),
+    (0x00000075, 7),
+    (0x00000028, 6),
+    (0x00000029, 6),
+    (0x0000002a, 6),
+    (0x00000007, 5),
+    (0x0000002b, 6),
+    (0x00000076, 7),
+    (0x0000002c, 6),
+    (0x00000008, 5),
+    (0x00000009, 5),
+    (0x0000002d, 6),
+    (0x00000077, 7),
+    (0x00000078, 7),
+    (0x00000079, 7),
+    (0x0000007a, 7),
+    (0x0000007b, 7),
+    (0x00007ffe, 15),
+    (0x000007fc, 11),
+    (0x00003ffd, 14),
+    (0x00001ffd, 13),
+    (0x0ffffffc, 28),
+    (0x000fffe6, 20),
+    (0x003fffd2, 22),
+    (0x000fffe7, 20),
+    (0x000fffe8, 20),
+    (0x003fffd3, 22),
+    (0x003fffd4, 22),
+    (0x003fffd5, 22),
+    (0x007fffd9, 23),
+    (0x003fffd6, 22),
+    (0x007fffda, 23),
+    (0x007fffdb, 23),
+    (0x007fffdc, 23),
+    (0x007fffdd, 23),
+    (0x007fffde, 23),
+    (0x00ffffeb, 24),
+    (0x007fffdf, 23),
+    (0x00ffffec, 24),
+    (0x00ffffed, 24),
+    (0x003fffd7, 22),
+    (0x007fffe0, 23),
+    (0x00ffffee, 24),
+    (0x007fffe1, 23),
+    (0x007fffe2, 23),
+    (0x007fffe3, 23),
+    (0x007fffe4, 23),
+    (0x001fffdc, 21),
+    (0x003fffd8, 22),
+    (0x007fffe5, 23),
+    (0x003fffd9, 22),
+    (0x007fffe6, 23),
+    (0x007fffe7, 23),
+    (0x00ffffef, 24),
+    (0x003fffda, 22),
+    (0x001fffdd, 21),
+    (0x000fffe9, 20),
+    (0x003fffdb, 22),
+    (0x003fffdc, 22),
+    (0x007fffe8, 23),
+    (0x007fffe9, 23),
+    (0x001fffde, 21),
+    (0x007fffea, 23),
+    (0x003fffdd, 22),
+    (0x003fffde, 22),
+    (0x00fffff0, 24),
+    (0x001fffdf, 21),
+    (0x003fffdf, 22),
+    (0x007fffeb, 23),
+    (0x007fffec, 23),
+    (0x001fffe0, 21),
+    (0x001fffe1, 21),
+    (0x003fffe0, 22),
+    (0x001fffe2, 21),
+    (0x007fffed, 23),
+    (0x003fffe1, 22),
+    (0x007fffee, 23),
+    (0x007fffef, 23),
+    (0x000fffea, 20),
+    (0x003fffe2, 22),
+    (0x003fffe3, 22),
+    (0x003fffe4, 22),
+    (0x007ffff0, 23),
+    (0x003fffe5, 22),
+    (0x003fffe6, 22),
+    (0x007ffff1, 23),
+    (0x03ffffe0, 26),
+    (0x03ffffe1, 26),
+    (0x000fffeb, 20),
+    (0x0007fff1, 19),
+    (0x003fffe7, 22),
+    (0x007ffff2, 23),
+    (0x003fffe8, 22),
+    (0x01ffffec, 25),
+    (0x03ffffe2, 26),
+    (0x03ffffe3, 26),
+    (0x03ffffe4, 26),
+    (0x07ffffde, 27),
+    (0x07ffffdf, 27),
+    (0x03ffffe5, 26),
+    (0x00fffff1, 24),
+    (0x01ffffed, 25),
+    (0x0007fff2, 19),
+    (0x001fffe3, 21),
+    (0x03ffffe6, 26),
+    (0x07ffffe0, 27),
+    (0x07ffffe1, 27),
+    (0x03ffffe7, 26),
+    (0x07ffffe2, 27),
+    (0x00fffff2, 24),
+    (0x001fffe4, 21),
+    (0x001fffe5, 21),
+    (0x03ffffe8, 26),
+    (0x03ffffe9, 26),
+    (0x0ffffffd, 28),
+    (0x07ffffe3, 27),
+    (0x07ffffe4, 27),
+    (0x07ffffe5, 27),
+    (0x000fffec, 20),
+    (0x00fffff3, 24),
+    (0x000fffed, 20),
+    (0x001fffe6, 21),
+    (0x003fffe9, 22),
+    (0x001fffe7, 21),
+    (0x001fffe8, 21),
+    (0x007ffff3, 23),
+    (0x003fffea, 22),
+    (0x003fffeb, 22),
+    (0x01ffffee, 25),
+    (0x01ffffef, 25),
+    (0x00fffff4, 24),
+    (0x00fffff5, 24),
+    (0x03ffffea, 26),
+    (0x007ffff4, 23),
+    (0x03ffffeb, 26),
+    (0x07ffffe6, 27),
+    (0x03ffffec, 26),
+    (0x03ffffed, 26),
+    (0x07ffffe7, 27),
+    (0x07ffffe8, 27),
+    (0x07ffffe9, 27),
+    (0x07ffffea, 27),
+    (0x07ffffeb, 27),
+    (0x0ffffffe, 28),
+    (0x07ffffec, 27),
+    (0x07ffffed, 27),
+    (0x07ffffee, 27),
+    (0x07ffffef, 27),
+    (0x07fffff0, 27),
+    (0x03ffffee, 26),
+    (0x3fffffff, 30),
+];
+
+/// EOS is never a real output byte: seeing it outside of the final padding
+/// means the encoder is misbehaving.
+const HUFFMAN_EOS_SYMBOL: u16 = 256;
+
+#[derive(Clone, Copy, PartialEq)]
+enum HuffmanTransStatus {
+    Ok,
+    /// the input byte does not continue any valid Huffman code at this state
+    InvalidCode,
+    /// the EOS symbol was completed in the middle of the string
+    EosInStream,
+}
+
+#[derive(Clone, Copy)]
+struct HuffmanTrans {
+    next_state: u16,
+    emit: [u8; 2],
+    emit_len: u8,
+    status: HuffmanTransStatus,
+}
+
+impl Default for HuffmanTrans {
+    fn default() -> Self {
+        HuffmanTrans {
+            next_state: 0,
+            emit: [0; 2],
+            emit_len: 0,
+            status: HuffmanTransStatus::InvalidCode,
+        }
+    }
+}
+
+/// One node of the Huffman code trie used to build the FSM. `sym` is the
+/// decoded symbol (0-256) once a leaf is reached, `depth` is the number of
+/// bits since the last symbol boundary (0 at the root) and `all_ones`
+/// tracks whether every one of those bits was a 1, which is exactly what
+/// RFC 7541 6.2 requires of trailing EOS padding.
+struct TrieNode {
+    children: [i32; 2],
+    sym: i32,
+    depth: u8,
+    all_ones: bool,
+}
+
+fn build_trie() -> Vec<TrieNode> {
+    let mut nodes = vec![TrieNode {
+        children: [-1, -1],
+        sym: -1,
+        depth: 0,
+        all_ones: true,
+    }];
+    for (symbol, &(code, len)) in HUFFMAN_CODES.iter().enumerate() {
+        let mut cur = 0usize;
+        for bitpos in (0..len).rev() {
+            let bit = ((code >> bitpos) & 1) as usize;
+            if nodes[cur].children[bit] < 0 {
+                let parent_depth = nodes[cur].depth;
+                let parent_all_ones = nodes[cur].all_ones;
+                nodes.push(TrieNode {
+                    children: [-1, -1],
+                    sym: -1,
+                    depth: parent_depth + 1,
+                    all_ones: parent_all_ones && bit == 1,
+                });
+                let newidx = (nodes.len() - 1) as i32;
+                nodes[cur].children[bit] = newidx;
+            }
+            cur = nodes[cur].children[bit] as usize;
+        }
+        nodes[cur].sym = symbol as i32;
+    }
+    nodes
+}
+
+/// Builds `HUFFMAN_FSM[state][byte]` by feeding each of the 256 possible
+/// byte values, bit by bit, through the trie starting from `state`. A
+/// state is simply the index of the trie node reached so far since the
+/// last emitted symbol (0 is the root, i.e. "no pending bits").
+fn build_fsm(nodes: &[TrieNode]) -> Vec<[HuffmanTrans; 256]> {
+    let mut fsm = vec![[HuffmanTrans::default(); 256]; nodes.len()];
+    for (state, row) in fsm.iter_mut().enumerate() {
+        for (byte, trans) in row.iter_mut().enumerate() {
+            let mut cur = state;
+            let mut emit = [0u8; 2];
+            let mut emit_len = 0u8;
+            let mut status = HuffmanTransStatus::Ok;
+            for bitpos in (0..8).rev() {
+                let bit = (byte >> bitpos) & 1;
+                let next = nodes[cur].children[bit];
+                if next < 0 {
+                    status = HuffmanTransStatus::InvalidCode;
+                    break;
+                }
+                let next = next as usize;
+                if nodes[next].sym >= 0 {
+                    if nodes[next].sym as u16 == HUFFMAN_EOS_SYMBOL {
+                        status = HuffmanTransStatus::EosInStream;
+                        break;
+                    }
+                    // emit_len can only reach 2: the shortest code is 5 bits,
+                    // so at most two symbols can complete within one byte.
+                    emit[emit_len as usize] = nodes[next].sym as u8;
+                    emit_len += 1;
+                    cur = 0;
+                } else {
+                    cur = next;
+                }
+            }
+            *trans = HuffmanTrans {
+                next_state: cur as u16,
+                emit,
+                emit_len,
+                status,
+            };
+        }
+    }
+    fsm
+}
+
+/// `(HUFFMAN_FSM[state][byte] table, per-node (depth, all_ones) info)`, the
+/// pair built once by [`build_fsm`]/[`build_trie`] and cached in
+/// [`HUFFMAN_FSM`].
+type HuffmanFsmTable = (Vec<[HuffmanTrans; 256]>, Vec<(u8, bool)>);
+
+static HUFFMAN_FSM: OnceLock<HuffmanFsmTable> = OnceLock::new();
+
+fn huffman_fsm() -> (&'static [[HuffmanTrans; 256]], &'static [(u8, bool)]) {
+    let (fsm, info) = HUFFMAN_FSM.get_or_init(|| {
+        let nodes = build_trie();
+        let fsm = build_fsm(&nodes);
+        let info = nodes.iter().map(|n| (n.depth, n.all_ones)).collect();
+        (fsm, info)
+    });
+    (fsm.as_slice(), info.as_slice())
+}
+
+/// Decodes a Huffman-coded HPACK string, a whole byte at a time.
+///
+/// `input` must be exactly the compressed string as delimited by its
+/// declared length; there is no separate terminator, so validity of the
+/// trailing bits (at most 7 bits, and they must all be 1, per RFC 7541
+/// 6.2) is checked once the whole buffer has been consumed. An EOS symbol
+/// completed before the end of the buffer is always a decode error.
+pub fn http2_decode_huffman(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
+    let (fsm, node_info) = huffman_fsm();
+    let mut state = 0usize;
+    let mut output = Vec::new();
+    for &byte in input {
+        let trans = &fsm[state][byte as usize];
+        match trans.status {
+            HuffmanTransStatus::Ok => {
+                output.extend_from_slice(&trans.emit[..trans.emit_len as usize]);
+                state = trans.next_state as usize;
+            }
+            HuffmanTransStatus::InvalidCode | HuffmanTransStatus::EosInStream => {
+                return Err(Err::Error((input, ErrorKind::MapOpt)));
+            }
+        }
+    }
+    let (depth, all_ones) = node_info[state];
+    if depth != 0 && !(depth <= 7 && all_ones) {
+        // truncated symbol, or padding that is not all-ones EOS padding
+        return Err(Err::Error((input, ErrorKind::Eof)));
+    }
+    Ok((&input[input.len()..], output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http2_decode_huffman() {
+        // "www.example.com" Huffman-encoded, from RFC 7541 C.4.1.
+        let buf: &[u8] = &[
+            0xf1, 0xe3, 0xc2, 0xe5, 0xf2, 0x3a, 0x6b, 0xa0, 0xab, 0x90, 0xf4, 0xff,
+        ];
+        let (rem, decoded) = http2_decode_huffman(buf).unwrap();
+        assert_eq!(rem.len(), 0);
+        assert_eq!(decoded, b"www.example.com");
+    }
+
+    #[test]
+    fn test_http2_decode_huffman_bad_padding() {
+        // a final byte whose padding bits are not all-ones is invalid
+        let buf: &[u8] = &[0xf1, 0xe3, 0xc2, 0xe5, 0xf2, 0x3a, 0x6b, 0xa0, 0xab, 0x90, 0xf4, 0x00];
+        assert!(http2_decode_huffman(buf).is_err());
+    }
+}
@@ -24,6 +24,7 @@ use nom::number::streaming::{be_u16, be_u32, be_u8};
 use nom::Err;
 use nom::IResult;
 use std::fmt;
+use std::rc::Rc;
 use std::str::FromStr;
 
 #[repr(u8)]
@@ -71,7 +72,6 @@ impl std::str::FromStr for HTTP2FrameType {
 
 #[derive(PartialEq, Debug)]
 pub struct HTTP2FrameHeader {
-    //we could add detection on (GOAWAY) additional data
     pub length: u32,
     pub ftype: u8,
     pub flags: u8,
@@ -142,15 +142,21 @@ impl std::str::FromStr for HTTP2ErrorCode {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct HTTP2FrameGoAway {
+    pub reserved: u8,
+    pub last_stream_id: u32,
     pub errorcode: u32, //HTTP2ErrorCode
+    pub debug_data: Vec<u8>,
 }
 
 named!(pub http2_parse_frame_goaway<HTTP2FrameGoAway>,
     do_parse!(
+        sid: bits!( tuple!( take_bits!(1u8),
+                            take_bits!(31u32) ) ) >>
         errorcode: be_u32 >>
-        (HTTP2FrameGoAway{errorcode})
+        debug_data: rest >>
+        (HTTP2FrameGoAway{reserved:sid.0, last_stream_id:sid.1, errorcode, debug_data:debug_data.to_vec()})
     )
 );
 
@@ -281,34 +287,40 @@ fn http2_frame_header_static(n: u64, dyn_headers: &HTTP2DynTable) -> Option<HTTP
     };
     if name.len() > 0 {
         return Some(HTTP2FrameHeaderBlock {
-            name: name.as_bytes().to_vec(),
-            value: value.as_bytes().to_vec(),
+            name: Rc::from(name.as_bytes()),
+            value: Rc::from(value.as_bytes()),
             error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeSuccess,
             sizeupdate: 0,
+            flags: 0,
         });
     } else {
         //use dynamic table
         if n == 0 {
             return Some(HTTP2FrameHeaderBlock {
-                name: Vec::new(),
-                value: Vec::new(),
+                name: Rc::from(&b""[..]),
+                value: Rc::from(&b""[..]),
                 error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeIndex0,
                 sizeupdate: 0,
+                flags: 0,
             });
         } else if dyn_headers.table.len() + HTTP2_STATIC_HEADERS_NUMBER < n as usize {
             return Some(HTTP2FrameHeaderBlock {
-                name: Vec::new(),
-                value: Vec::new(),
+                name: Rc::from(&b""[..]),
+                value: Rc::from(&b""[..]),
                 error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeNotIndexed,
                 sizeupdate: 0,
+                flags: 0,
             });
         } else {
             let indyn = dyn_headers.table.len() - (n as usize - HTTP2_STATIC_HEADERS_NUMBER);
             let headcopy = HTTP2FrameHeaderBlock {
-                name: dyn_headers.table[indyn].name.to_vec(),
-                value: dyn_headers.table[indyn].value.to_vec(),
+                // the dynamic table entry may be evicted later on, but the Rc handle
+                // keeps the underlying bytes alive for as long as this block needs them
+                name: dyn_headers.table[indyn].name.clone(),
+                value: dyn_headers.table[indyn].value.clone(),
                 error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeSuccess,
                 sizeupdate: 0,
+                flags: 0,
             };
             return Some(headcopy);
         }
@@ -324,6 +336,7 @@ pub enum HTTP2HeaderDecodeStatus {
     HTTP2HeaderDecodeNotIndexed = 0x81,
     HTTP2HeaderDecodeIntegerOverflow = 0x82,
     HTTP2HeaderDecodeIndex0 = 0x83,
+    HTTP2HeaderDecodeAmplification = 0x84,
 }
 
 impl fmt::Display for HTTP2HeaderDecodeStatus {
@@ -334,10 +347,76 @@ impl fmt::Display for HTTP2HeaderDecodeStatus {
 
 #[derive(Clone, Debug)]
 pub struct HTTP2FrameHeaderBlock {
-    pub name: Vec<u8>,
-    pub value: Vec<u8>,
+    // Rc, rather than a fresh Vec<u8>, so indexed references into the HPACK dynamic
+    // table (which can be replayed many times before eviction) are O(1) to clone
+    pub name: Rc<[u8]>,
+    pub value: Rc<[u8]>,
     pub error: HTTP2HeaderDecodeStatus,
     pub sizeupdate: u64,
+    // bitmask of HTTP2_HEADER_FLAG_* RFC 7540 8.1.2 validity issues found on
+    // this header, only meaningful when error is HTTP2HeaderDecodeSuccess
+    pub flags: u8,
+}
+
+// the decoded header name contains an uppercase ASCII character, which HTTP/2
+// forbids regardless of how it was HPACK-encoded
+pub const HTTP2_HEADER_FLAG_UPPERCASE: u8 = 0x01;
+// connection-specific header fields are not allowed over HTTP/2 (RFC 7540 8.1.2.2)
+pub const HTTP2_HEADER_FLAG_ILLEGAL_CONNECTION_HEADER: u8 = 0x02;
+// a pseudo-header field appeared after a regular header field (RFC 7540 8.1.2.1)
+pub const HTTP2_HEADER_FLAG_PSEUDOHEADER_AFTER_REGULAR: u8 = 0x04;
+// the same pseudo-header field appeared more than once in the header list
+pub const HTTP2_HEADER_FLAG_PSEUDOHEADER_DUPLICATED: u8 = 0x08;
+
+const HTTP2_PSEUDOHEADER_METHOD: u8 = 0x01;
+const HTTP2_PSEUDOHEADER_PATH: u8 = 0x02;
+const HTTP2_PSEUDOHEADER_SCHEME: u8 = 0x04;
+const HTTP2_PSEUDOHEADER_AUTHORITY: u8 = 0x08;
+const HTTP2_PSEUDOHEADER_STATUS: u8 = 0x10;
+
+fn http2_pseudoheader_bit(name: &[u8]) -> Option<u8> {
+    match name {
+        b":method" => Some(HTTP2_PSEUDOHEADER_METHOD),
+        b":path" => Some(HTTP2_PSEUDOHEADER_PATH),
+        b":scheme" => Some(HTTP2_PSEUDOHEADER_SCHEME),
+        b":authority" => Some(HTTP2_PSEUDOHEADER_AUTHORITY),
+        b":status" => Some(HTTP2_PSEUDOHEADER_STATUS),
+        _ => None,
+    }
+}
+
+// Computes the RFC 7540 8.1.2 validity flags for one decoded header, given
+// the ordering/duplication state accumulated so far over the header list.
+// Applies to every header regardless of whether it was HPACK-indexed or
+// literally encoded, since an indexed reference can carry a pseudo-header
+// (eg index 2 is ":method: GET") just as well as a literal one.
+fn http2_header_validity_flags(
+    name: &[u8], value: &[u8], seen_regular_header: &mut bool, seen_pseudoheaders: &mut u8,
+) -> u8 {
+    let mut flags = 0;
+    if name.iter().any(u8::is_ascii_uppercase) {
+        flags |= HTTP2_HEADER_FLAG_UPPERCASE;
+    }
+    if name.eq_ignore_ascii_case(b"connection")
+        || name.eq_ignore_ascii_case(b"transfer-encoding")
+        || name.eq_ignore_ascii_case(b"keep-alive")
+        || name.eq_ignore_ascii_case(b"upgrade")
+        || (name.eq_ignore_ascii_case(b"te") && !value.eq_ignore_ascii_case(b"trailers"))
+    {
+        flags |= HTTP2_HEADER_FLAG_ILLEGAL_CONNECTION_HEADER;
+    }
+    if let Some(bit) = http2_pseudoheader_bit(name) {
+        if *seen_regular_header {
+            flags |= HTTP2_HEADER_FLAG_PSEUDOHEADER_AFTER_REGULAR;
+        }
+        if *seen_pseudoheaders & bit != 0 {
+            flags |= HTTP2_HEADER_FLAG_PSEUDOHEADER_DUPLICATED;
+        }
+        *seen_pseudoheaders |= bit;
+    } else if !name.is_empty() {
+        *seen_regular_header = true;
+    }
+    return flags;
 }
 
 fn http2_parse_headers_block_indexed<'a>(
@@ -363,7 +442,7 @@ fn http2_parse_headers_block_indexed<'a>(
     }
 }
 
-fn http2_parse_headers_block_string(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
+fn http2_parse_headers_block_string(input: &[u8]) -> IResult<&[u8], Rc<[u8]>> {
     fn parser(input: &[u8]) -> IResult<&[u8], (u8, u8)> {
         bits!(input, tuple!(take_bits!(1u8), take_bits!(7u8)))
     }
@@ -374,10 +453,10 @@ fn http2_parse_headers_block_string(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
     }
     let (i3, data) = take!(i2, stringlen as usize)?;
     if huffslen.0 == 0 {
-        return Ok((i3, data.to_vec()));
+        return Ok((i3, Rc::from(data)));
     } else {
-        let (_, val) = bits!(data, many0!(huffman::http2_decode_huffman))?;
-        return Ok((i3, val));
+        let (_, val) = huffman::http2_decode_huffman(data)?;
+        return Ok((i3, Rc::from(val)));
     }
 }
 
@@ -398,7 +477,7 @@ fn http2_parse_headers_block_literal_common<'a>(
             )),
             None => Ok((
                 input,
-                Vec::new(),
+                Rc::from(&b""[..]),
                 HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeNotIndexed,
             )),
         }
@@ -411,6 +490,7 @@ fn http2_parse_headers_block_literal_common<'a>(
             value,
             error,
             sizeupdate: 0,
+            flags: 0,
         },
     ));
 }
@@ -436,10 +516,11 @@ fn http2_parse_headers_block_literal_incindex<'a>(
     match r {
         Ok((r, head)) => {
             let headcopy = HTTP2FrameHeaderBlock {
-                name: head.name.to_vec(),
-                value: head.value.to_vec(),
+                name: head.name.clone(),
+                value: head.value.clone(),
                 error: head.error,
                 sizeupdate: 0,
+                flags: 0,
             };
             if head.error == HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeSuccess {
                 dyn_headers.current_size += 32 + headcopy.name.len() + headcopy.value.len();
@@ -557,10 +638,11 @@ fn http2_parse_headers_block_dynamic_size<'a>(
         return Ok((
             i3,
             HTTP2FrameHeaderBlock {
-                name: Vec::new(),
-                value: Vec::new(),
+                name: Rc::from(&b""[..]),
+                value: Rc::from(&b""[..]),
                 error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeIntegerOverflow,
                 sizeupdate: 0,
+                flags: 0,
             },
         ));
     }
@@ -581,10 +663,11 @@ fn http2_parse_headers_block_dynamic_size<'a>(
     return Ok((
         i3,
         HTTP2FrameHeaderBlock {
-            name: Vec::new(),
-            value: Vec::new(),
+            name: Rc::from(&b""[..]),
+            value: Rc::from(&b""[..]),
             error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeSizeUpdate,
             sizeupdate: maxsize2,
+            flags: 0,
         },
     ));
 }
@@ -606,6 +689,77 @@ fn http2_parse_headers_block<'a>(
     }
 }
 
+// Below this decoded size, we do not bother checking the expansion ratio,
+// so that legitimately small, heavily indexed header sets do not false
+// positive just because they are a small number of bytes on the wire.
+pub const HTTP2_MIN_HEADER_EXPANSION_SIZE: usize = 4096;
+// A compressed input that expands to more than this many times its own
+// size is considered an HPACK decompression bomb.
+pub const HTTP2_MAX_HEADER_EXPANSION_RATIO: usize = 100;
+
+// Parses as many header blocks as fit in `input`, shared by HEADERS,
+// PUSH_PROMISE and CONTINUATION. A single HPACK-indexed byte can expand
+// into an arbitrarily large previously-seen name+value pair, so we track
+// the running decoded size against the compressed input size and bail out
+// with a HTTP2HeaderDecodeAmplification marker block rather than keep
+// decoding an attacker-controlled amplification ratio.
+//
+// `compressed_size`/`decoded_size` and `seen_regular_header`/
+// `seen_pseudoheaders` all live on `cont_state` and accumulate across
+// calls rather than being re-initialized as locals, since a single
+// logical header list is routinely split across a HEADERS frame and one
+// or more CONTINUATION frames: tracking the bomb-ratio totals per-call
+// would let an attacker spread the amplification payload across many
+// frames, each individually under the ratio, and tracking the
+// pseudo-header state per-call would let a duplicated or late
+// pseudo-header slip through undetected right at a frame boundary.
+fn http2_parse_headers_block_list<'a>(
+    input: &'a [u8], dyn_headers: &mut HTTP2DynTable, cont_state: &mut HTTP2ContinuationState,
+) -> IResult<&'a [u8], Vec<HTTP2FrameHeaderBlock>> {
+    cont_state.compressed_size += input.len();
+    let mut i3 = input;
+    let mut blocks = Vec::new();
+    while i3.len() > 0 {
+        match http2_parse_headers_block(i3, dyn_headers) {
+            Ok((rem, mut b)) => {
+                debug_validate_bug_on!(i3.len() == rem.len());
+                if i3.len() == rem.len() {
+                    //infinite loop
+                    return Err(Err::Error((input, ErrorKind::Eof)));
+                }
+                i3 = rem;
+                cont_state.decoded_size += b.name.len() + b.value.len();
+                if b.error == HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeSuccess {
+                    b.flags = http2_header_validity_flags(
+                        &b.name,
+                        &b.value,
+                        &mut cont_state.seen_regular_header,
+                        &mut cont_state.seen_pseudoheaders,
+                    );
+                }
+                blocks.push(b);
+                if cont_state.decoded_size > HTTP2_MIN_HEADER_EXPANSION_SIZE
+                    && cont_state.decoded_size
+                        > cont_state.compressed_size.saturating_mul(HTTP2_MAX_HEADER_EXPANSION_RATIO)
+                {
+                    blocks.push(HTTP2FrameHeaderBlock {
+                        name: Rc::from(&b""[..]),
+                        value: Rc::from(&b""[..]),
+                        error: HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeAmplification,
+                        sizeupdate: 0,
+                        flags: 0,
+                    });
+                    break;
+                }
+            }
+            Err(x) => {
+                return Err(x);
+            }
+        }
+    }
+    return Ok((i3, blocks));
+}
+
 #[derive(Clone, Debug)]
 pub struct HTTP2FrameHeaders {
     pub padlength: Option<u8>,
@@ -619,34 +773,72 @@ pub const HTTP2_FLAG_HEADER_END_HEADERS: u8 = 0x4;
 pub const HTTP2_FLAG_HEADER_PADDED: u8 = 0x8;
 const HTTP2_FLAG_HEADER_PRIORITY: u8 = 0x20;
 
+// A HEADERS/PUSH_PROMISE frame that does not set END_HEADERS promises one
+// or more CONTINUATION frames to follow. Per-stream state threaded across
+// all of them so we can cap both the number of fragments and their total
+// size, rather than letting a peer tie up decoding with an unbounded
+// stream of tiny header-block fragments (the "CONTINUATION flood" DoS).
+#[derive(Debug)]
+pub struct HTTP2ContinuationState {
+    pub frames: u32,
+    pub fragment_len: u64,
+    // RFC 7540 8.1.2 validity state for the header list being assembled
+    // across this HEADERS/PUSH_PROMISE frame and any CONTINUATION frames
+    // that follow it, so duplication/ordering checks see the whole list.
+    pub seen_regular_header: bool,
+    pub seen_pseudoheaders: u8,
+    // HPACK decompression-bomb accounting for the header list being
+    // assembled across this HEADERS/PUSH_PROMISE frame and any
+    // CONTINUATION frames that follow it, so the expansion ratio is
+    // checked against the sequence's totals rather than resetting (and
+    // so under-counting) at every frame boundary.
+    pub compressed_size: usize,
+    pub decoded_size: usize,
+}
+
+impl HTTP2ContinuationState {
+    pub fn new() -> Self {
+        HTTP2ContinuationState {
+            frames: 0,
+            fragment_len: 0,
+            seen_regular_header: false,
+            seen_pseudoheaders: 0,
+            compressed_size: 0,
+            decoded_size: 0,
+        }
+    }
+}
+
+pub const HTTP2_MAX_CONTINUATION_FRAMES: u32 = 64;
+pub const HTTP2_MAX_CONTINUATION_FRAGMENT_SIZE: u64 = 64 * 1024;
+
+fn http2_continuation_track<'a>(
+    input: &'a [u8], cont_state: &mut HTTP2ContinuationState,
+) -> IResult<&'a [u8], ()> {
+    cont_state.frames += 1;
+    cont_state.fragment_len += input.len() as u64;
+    if cont_state.frames > HTTP2_MAX_CONTINUATION_FRAMES
+        || cont_state.fragment_len > HTTP2_MAX_CONTINUATION_FRAGMENT_SIZE
+    {
+        return Err(Err::Error((input, ErrorKind::TooLarge)));
+    }
+    return Ok((input, ()));
+}
+
 pub fn http2_parse_frame_headers<'a>(
     input: &'a [u8], flags: u8, dyn_headers: &mut HTTP2DynTable,
+    cont_state: &mut HTTP2ContinuationState,
 ) -> IResult<&'a [u8], HTTP2FrameHeaders> {
+    http2_continuation_track(input, cont_state)?;
     let (i2, padlength) = cond!(input, flags & HTTP2_FLAG_HEADER_PADDED != 0, be_u8)?;
-    let (mut i3, priority) = cond!(
+    let (i3, priority) = cond!(
         i2,
         flags & HTTP2_FLAG_HEADER_PRIORITY != 0,
         http2_parse_headers_priority
     )?;
-    let mut blocks = Vec::new();
-    while i3.len() > 0 {
-        match http2_parse_headers_block(i3, dyn_headers) {
-            Ok((rem, b)) => {
-                blocks.push(b);
-                debug_validate_bug_on!(i3.len() == rem.len());
-                if i3.len() == rem.len() {
-                    //infinite loop
-                    return Err(Err::Error((input, ErrorKind::Eof)));
-                }
-                i3 = rem;
-            }
-            Err(x) => {
-                return Err(x);
-            }
-        }
-    }
+    let (i4, blocks) = http2_parse_headers_block_list(i3, dyn_headers, cont_state)?;
     return Ok((
-        i3,
+        i4,
         HTTP2FrameHeaders {
             padlength,
             priority,
@@ -665,28 +857,14 @@ pub struct HTTP2FramePushPromise {
 
 pub fn http2_parse_frame_push_promise<'a>(
     input: &'a [u8], flags: u8, dyn_headers: &mut HTTP2DynTable,
+    cont_state: &mut HTTP2ContinuationState,
 ) -> IResult<&'a [u8], HTTP2FramePushPromise> {
+    http2_continuation_track(input, cont_state)?;
     let (i2, padlength) = cond!(input, flags & HTTP2_FLAG_HEADER_PADDED != 0, be_u8)?;
-    let (mut i3, stream_id) = bits!(i2, tuple!(take_bits!(1u8), take_bits!(31u32)))?;
-    let mut blocks = Vec::new();
-    while i3.len() > 0 {
-        match http2_parse_headers_block(i3, dyn_headers) {
-            Ok((rem, b)) => {
-                blocks.push(b);
-                debug_validate_bug_on!(i3.len() == rem.len());
-                if i3.len() == rem.len() {
-                    //infinite loop
-                    return Err(Err::Error((input, ErrorKind::Eof)));
-                }
-                i3 = rem;
-            }
-            Err(x) => {
-                return Err(x);
-            }
-        }
-    }
+    let (i3, stream_id) = bits!(i2, tuple!(take_bits!(1u8), take_bits!(31u32)))?;
+    let (i4, blocks) = http2_parse_headers_block_list(i3, dyn_headers, cont_state)?;
     return Ok((
-        i3,
+        i4,
         HTTP2FramePushPromise {
             padlength,
             reserved: stream_id.0,
@@ -702,27 +880,11 @@ pub struct HTTP2FrameContinuation {
 }
 
 pub fn http2_parse_frame_continuation<'a>(
-    input: &'a [u8], dyn_headers: &mut HTTP2DynTable,
+    input: &'a [u8], dyn_headers: &mut HTTP2DynTable, cont_state: &mut HTTP2ContinuationState,
 ) -> IResult<&'a [u8], HTTP2FrameContinuation> {
-    let mut i3 = input;
-    let mut blocks = Vec::new();
-    while i3.len() > 0 {
-        match http2_parse_headers_block(i3, dyn_headers) {
-            Ok((rem, b)) => {
-                blocks.push(b);
-                debug_validate_bug_on!(i3.len() == rem.len());
-                if i3.len() == rem.len() {
-                    //infinite loop
-                    return Err(Err::Error((input, ErrorKind::Eof)));
-                }
-                i3 = rem;
-            }
-            Err(x) => {
-                return Err(x);
-            }
-        }
-    }
-    return Ok((i3, HTTP2FrameContinuation { blocks }));
+    http2_continuation_track(input, cont_state)?;
+    let (i2, blocks) = http2_parse_headers_block_list(input, dyn_headers, cont_state)?;
+    return Ok((i2, HTTP2FrameContinuation { blocks }));
 }
 
 #[repr(u16)]
@@ -936,8 +1098,8 @@ mod tests {
         match r0 {
             Ok((remainder, hd)) => {
                 // Check the first message.
-                assert_eq!(hd.name, ":method".as_bytes().to_vec());
-                assert_eq!(hd.value, "GET".as_bytes().to_vec());
+                assert_eq!(hd.name.as_ref(), ":method".as_bytes());
+                assert_eq!(hd.value.as_ref(), "GET".as_bytes());
                 // And we should have no bytes left.
                 assert_eq!(remainder.len(), 0);
             }
@@ -953,8 +1115,8 @@ mod tests {
         match r1 {
             Ok((remainder, hd)) => {
                 // Check the first message.
-                assert_eq!(hd.name, "accept".as_bytes().to_vec());
-                assert_eq!(hd.value, "*/*".as_bytes().to_vec());
+                assert_eq!(hd.name.as_ref(), "accept".as_bytes());
+                assert_eq!(hd.value.as_ref(), "*/*".as_bytes());
                 // And we should have no bytes left.
                 assert_eq!(remainder.len(), 0);
                 assert_eq!(dynh.table.len(), 1);
@@ -973,8 +1135,8 @@ mod tests {
         match result {
             Ok((remainder, hd)) => {
                 // Check the first message.
-                assert_eq!(hd.name, ":authority".as_bytes().to_vec());
-                assert_eq!(hd.value, "localhost:3000".as_bytes().to_vec());
+                assert_eq!(hd.name.as_ref(), ":authority".as_bytes());
+                assert_eq!(hd.value.as_ref(), "localhost:3000".as_bytes());
                 // And we should have no bytes left.
                 assert_eq!(remainder.len(), 0);
                 assert_eq!(dynh.table.len(), 2);
@@ -991,8 +1153,8 @@ mod tests {
         match r3 {
             Ok((remainder, hd)) => {
                 // same as before
-                assert_eq!(hd.name, ":authority".as_bytes().to_vec());
-                assert_eq!(hd.value, "localhost:3000".as_bytes().to_vec());
+                assert_eq!(hd.name.as_ref(), ":authority".as_bytes());
+                assert_eq!(hd.value.as_ref(), "localhost:3000".as_bytes());
                 // And we should have no bytes left.
                 assert_eq!(remainder.len(), 0);
                 assert_eq!(dynh.table.len(), 2);
@@ -1027,8 +1189,8 @@ mod tests {
         match r2 {
             Ok((remainder, hd)) => {
                 // Check the first message.
-                assert_eq!(hd.name, ":path".as_bytes().to_vec());
-                assert_eq!(hd.value, "/doc/manual/html/index.html".as_bytes().to_vec());
+                assert_eq!(hd.name.as_ref(), ":path".as_bytes());
+                assert_eq!(hd.value.as_ref(), "/doc/manual/html/index.html".as_bytes());
                 // And we should have no bytes left.
                 assert_eq!(remainder.len(), 0);
                 assert_eq!(dynh.table.len(), 2);
@@ -1221,4 +1383,95 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_http2_parse_frame_goaway() {
+        let buf: &[u8] = &[
+            0x00, 0x00, 0x00, 0x05, // reserved=0, last_stream_id=5
+            0x00, 0x00, 0x00, 0x00, // errorcode = NO_ERROR
+            b'b', b'y', b'e',
+        ];
+        let (rem, frame) = http2_parse_frame_goaway(buf).unwrap();
+        assert_eq!(frame.reserved, 0);
+        assert_eq!(frame.last_stream_id, 5);
+        assert_eq!(frame.errorcode, 0);
+        assert_eq!(frame.debug_data, b"bye");
+        assert_eq!(rem.len(), 0);
+    }
+
+    #[test]
+    fn test_http2_header_block_dyn_table_rc_shared() {
+        let mut dynh = HTTP2DynTable::new();
+        // Literal header field with incremental indexing, new name: adds
+        // "n": "v" to the dynamic table.
+        let buf: &[u8] = &[0x40, 0x01, b'n', 0x01, b'v'];
+        let (_, head) = http2_parse_headers_block_literal_incindex(buf, &mut dynh).unwrap();
+        assert_eq!(dynh.table.len(), 1);
+        // Indexed reference to that same dynamic entry (index 62 is the
+        // first dynamic slot, right after the 61 static entries) must hand
+        // back the exact same backing allocation, not a fresh copy.
+        let idxbuf: &[u8] = &[0x80 | 62];
+        let (_, head2) = http2_parse_headers_block_indexed(idxbuf, &dynh).unwrap();
+        assert!(Rc::ptr_eq(&head.name, &head2.name));
+        assert!(Rc::ptr_eq(&head.value, &head2.value));
+    }
+
+    #[test]
+    fn test_http2_pseudoheader_duplicate_across_continuation() {
+        let mut dynh = HTTP2DynTable::new();
+        let mut cont_state = HTTP2ContinuationState::new();
+        // ":status: 200", literal header field without indexing, new name,
+        // as if decoded from the initial HEADERS frame.
+        let buf1: &[u8] = &[
+            0x00, 0x07, b':', b's', b't', b'a', b't', b'u', b's', 0x03, b'2', b'0', b'0',
+        ];
+        let (_, blocks1) = http2_parse_headers_block_list(buf1, &mut dynh, &mut cont_state).unwrap();
+        assert_eq!(blocks1.len(), 1);
+        assert_eq!(blocks1[0].flags & HTTP2_HEADER_FLAG_PSEUDOHEADER_DUPLICATED, 0);
+
+        // Same pseudo-header again, as if decoded from a CONTINUATION frame
+        // that follows: the duplication must still be caught even though
+        // this is a fresh call to http2_parse_headers_block_list.
+        let buf2: &[u8] = &[
+            0x00, 0x07, b':', b's', b't', b'a', b't', b'u', b's', 0x03, b'4', b'0', b'4',
+        ];
+        let (_, blocks2) = http2_parse_headers_block_list(buf2, &mut dynh, &mut cont_state).unwrap();
+        assert_eq!(blocks2.len(), 1);
+        assert_ne!(
+            blocks2[0].flags & HTTP2_HEADER_FLAG_PSEUDOHEADER_DUPLICATED,
+            0
+        );
+    }
+
+    #[test]
+    fn test_http2_continuation_flood_cap() {
+        let mut cont_state = HTTP2ContinuationState::new();
+        for _ in 0..HTTP2_MAX_CONTINUATION_FRAMES {
+            assert!(http2_continuation_track(&[], &mut cont_state).is_ok());
+        }
+        // One CONTINUATION frame more than the cap must be rejected.
+        assert!(http2_continuation_track(&[], &mut cont_state).is_err());
+    }
+
+    #[test]
+    fn test_http2_headers_amplification_accumulates_across_frames() {
+        let mut dynh = HTTP2DynTable::new();
+        let mut cont_state = HTTP2ContinuationState::new();
+        // Simulate a prior HEADERS frame that already decoded close to the
+        // minimum-size floor, to show the check looks at the running total
+        // carried on `cont_state` rather than resetting per call.
+        cont_state.compressed_size = 1;
+        cont_state.decoded_size = HTTP2_MIN_HEADER_EXPANSION_SIZE - 6;
+        // A single small indexed header block (":method: GET", 1 compressed
+        // byte decoding to 10 bytes) tips the cumulative total over both
+        // the minimum-size floor and the compressed-size*ratio bound, even
+        // though this frame's own bytes are tiny.
+        let buf: &[u8] = &[0x82];
+        let (_, blocks) = http2_parse_headers_block_list(buf, &mut dynh, &mut cont_state).unwrap();
+        assert!(blocks
+            .iter()
+            .any(|b| b.error == HTTP2HeaderDecodeStatus::HTTP2HeaderDecodeAmplification));
+        assert_eq!(cont_state.decoded_size, HTTP2_MIN_HEADER_EXPANSION_SIZE + 4);
+        assert_eq!(cont_state.compressed_size, 2);
+    }
 }
@@ -0,0 +1,9 @@
+//! Parser/encoder for Basic Encoding Rules (BER) and Distinguished Encoding Rules (DER)
+//!
+//! Only the `oid` module is vendored here; the rest of the upstream crate
+//! (BER/DER value parsers, `der_parser::der`, etc.) lives alongside it in a
+//! full checkout and is intentionally not duplicated in this tree.
+
+pub mod oid;
+#[cfg(feature = "registry")]
+pub mod oid_registry;
@@ -0,0 +1,610 @@
+//! ASN.1 Object Identifiers (OID).
+//!
+//! An OID is stored in its DER-encoded form (a sequence of base-128 encoded
+//! arcs, the first two arcs folded together as `X*40+Y`), so comparisons and
+//! the [`oid!`] macro can work directly on bytes instead of re-parsing on
+//! every use.
+
+use std::fmt;
+use std::num::IntErrorKind;
+use std::str::FromStr;
+
+/// Borrowed-or-owned DER bytes backing an [`Oid`]. A hand-rolled stand-in
+/// for `Cow<'a, [u8]>`: `Cow`'s `PartialEq` impl is hand-written rather than
+/// `#[derive]`d, which opts it out of Rust's "structural match" eligibility
+/// and in turn `Oid` itself. Deriving `PartialEq`/`Eq` here instead is what
+/// lets a `const Oid` appear directly as a `match` pattern (see
+/// [`Oid::new`]): a const always holds the `Borrowed` variant, whose
+/// `&[u8]` is structurally matchable (`Vec<u8>` is not).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum OidBytes<'a> {
+    Borrowed(&'a [u8]),
+    Owned(Vec<u8>),
+}
+
+impl<'a> OidBytes<'a> {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            OidBytes::Borrowed(b) => b,
+            OidBytes::Owned(v) => v,
+        }
+    }
+}
+
+/// An ASN.1 Object Identifier, stored as its DER encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Oid<'a> {
+    asn1: OidBytes<'a>,
+}
+
+impl<'a> Oid<'a> {
+    /// Builds an `Oid` from an already DER-encoded byte slice, in a `const`
+    /// context. This is what [`oid!`] expands to so that OID constants can
+    /// be declared `const` and used directly as `match` patterns; unlike
+    /// [`Oid::from`], the arcs are assumed to already be valid, since they
+    /// were validated when the literal was encoded.
+    pub const fn new(asn1: &'static [u8]) -> Oid<'static> {
+        Oid {
+            asn1: OidBytes::Borrowed(asn1),
+        }
+    }
+
+    /// Builds an `Oid` at runtime from a list of arcs, validating and
+    /// DER-encoding them.
+    pub fn from(arcs: &[u64]) -> Result<Oid<'static>, OidParseError> {
+        if arcs.len() < 2 {
+            return Err(OidParseError::TooShort);
+        }
+        if arcs[0] > 2 {
+            return Err(OidParseError::FirstComponentTooLarge);
+        }
+        if arcs[0] < 2 && arcs[1] >= 40 {
+            return Err(OidParseError::SecondComponentTooLarge);
+        }
+        // The folded `X*40+Y` value is just another arc as far as the DER
+        // encoding goes: when `arcs[0] == 2`, `arcs[1]` is unbounded, so the
+        // combined value routinely exceeds 127 and needs the same
+        // multi-byte base-128 encoding as any other arc (it does not fit a
+        // single byte merely because `arcs[0] <= 2`).
+        let mut asn1 = Vec::new();
+        push_base128_arc(arcs[0] * 40 + arcs[1], &mut asn1);
+        for &arc in &arcs[2..] {
+            push_base128_arc(arc, &mut asn1);
+        }
+        Ok(Oid {
+            asn1: OidBytes::Owned(asn1),
+        })
+    }
+
+    /// Returns the DER encoding of this OID.
+    pub fn bytes(&self) -> &[u8] {
+        self.asn1.as_slice()
+    }
+
+    /// Looks up this OID's canonical short name in the default global
+    /// [`crate::oid_registry::OidRegistry`], if it's known there.
+    #[cfg(feature = "registry")]
+    pub fn registry_name(&self) -> Option<&'static str> {
+        crate::oid_registry::oid_registry().get(self).map(|e| e.sn)
+    }
+}
+
+impl<'a> fmt::Display for Oid<'a> {
+    /// Renders the OID as dotted decimal, reversing [`Oid::from`]'s
+    /// encoding: the first base-128 value is decoded like any other arc,
+    /// then split back into `X` and `Y` (`X` clamped to 2 when the value is
+    /// `>= 80`, since that's the only arc where `X*40+Y` can't be inverted
+    /// by plain `/40` and `%40`), and every following arc is decoded from
+    /// its base-128 continuation bytes.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut arcs = decode_arcs_u64(self.bytes());
+        if let Some(first) = arcs.next() {
+            write!(f, "{}", first)?;
+        }
+        for arc in arcs {
+            write!(f, ".{}", arc)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Oid<'static> {
+    type Err = OidParseError;
+
+    /// Parses a dotted-decimal OID, e.g. `"1.2.840.113549.1.1.1"`, directly
+    /// into its DER byte form via [`Oid::from`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(OidParseError::Empty);
+        }
+        let mut arcs = Vec::new();
+        for (idx, part) in s.split('.').enumerate() {
+            let arc: u64 = part.parse().map_err(|e: std::num::ParseIntError| match e.kind() {
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                    OidParseError::IntegerOverflow(idx)
+                }
+                _ => OidParseError::InvalidDigit(idx),
+            })?;
+            arcs.push(arc);
+        }
+        Oid::from(&arcs)
+    }
+}
+
+/// Decodes the arcs of a DER-encoded OID as plain `u64`s, for [`Display`].
+/// Mirrors `bigint_support::BigUintArcs` one-for-one, minus the
+/// arbitrary-precision arithmetic, since `Display` doesn't need the
+/// `bigint` feature to print ordinary-sized OIDs.
+fn decode_arcs_u64(data: &[u8]) -> impl Iterator<Item = u64> + '_ {
+    U64Arcs {
+        data,
+        started: false,
+        pending_second: None,
+    }
+}
+
+struct U64Arcs<'a> {
+    data: &'a [u8],
+    started: bool,
+    pending_second: Option<u64>,
+}
+
+impl<'a> Iterator for U64Arcs<'a> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if let Some(second) = self.pending_second.take() {
+            return Some(second);
+        }
+        if !self.started {
+            self.started = true;
+            let value = take_base128_u64(&mut self.data)?;
+            let (x, y) = if value >= 80 {
+                (2, value - 80)
+            } else {
+                (value / 40, value % 40)
+            };
+            self.pending_second = Some(y);
+            return Some(x);
+        }
+        take_base128_u64(&mut self.data)
+    }
+}
+
+/// Reads one base-128, big-endian, continuation-bit-terminated value off the
+/// front of `data`, advancing it past the bytes consumed. Shared by the
+/// folded first subidentifier and every plain trailing arc in
+/// [`U64Arcs`], since both are decoded identically.
+fn take_base128_u64(data: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    loop {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+        value = (value << 7) | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    Some(value)
+}
+
+/// Appends the base-128 (big-endian, continuation-bit) encoding of one arc.
+/// Not `const fn`: it grows a `Vec`, which is only usable at runtime (see
+/// [`oid_encode_padded`] for the const-eval counterpart used by the
+/// [`oid!`] macro).
+fn push_base128_arc(arc: u64, out: &mut Vec<u8>) {
+    let (buf, n) = base128_digits(arc);
+    let mut i = n;
+    while i > 0 {
+        i -= 1;
+        let continuation = if i != 0 { 0x80 } else { 0x00 };
+        out.push(buf[i] | continuation);
+    }
+}
+
+/// Splits `arc` into little-endian base-128 digits. Shared by the runtime
+/// and const-eval encoders below.
+const fn base128_digits(mut arc: u64) -> ([u8; 10], usize) {
+    // a u64 needs at most 10 base-128 digits
+    let mut buf = [0u8; 10];
+    let mut n = 0;
+    buf[n] = (arc & 0x7f) as u8;
+    n += 1;
+    arc >>= 7;
+    while arc > 0 {
+        buf[n] = (arc & 0x7f) as u8;
+        n += 1;
+        arc >>= 7;
+    }
+    (buf, n)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OidParseError {
+    /// the string to parse was empty
+    Empty,
+    /// an OID needs at least two arcs
+    TooShort,
+    /// the first arc of an OID must be 0, 1 or 2
+    FirstComponentTooLarge,
+    /// when the first arc is 0 or 1, the second arc must be less than 40
+    SecondComponentTooLarge,
+    /// the arc at this (0-indexed) position contains a non-digit character
+    InvalidDigit(usize),
+    /// the arc at this (0-indexed) position does not fit in a `u64`
+    IntegerOverflow(usize),
+}
+
+impl fmt::Display for OidParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OidParseError::Empty => write!(f, "OID string must not be empty"),
+            OidParseError::TooShort => write!(f, "OID must have at least two arcs"),
+            OidParseError::FirstComponentTooLarge => {
+                write!(f, "first OID arc must be 0, 1 or 2")
+            }
+            OidParseError::SecondComponentTooLarge => {
+                write!(f, "second OID arc must be < 40 when the first arc is 0 or 1")
+            }
+            OidParseError::InvalidDigit(idx) => {
+                write!(f, "arc {} is not a valid non-negative integer", idx)
+            }
+            OidParseError::IntegerOverflow(idx) => write!(f, "arc {} overflows u64", idx),
+        }
+    }
+}
+
+impl std::error::Error for OidParseError {}
+
+/// Maximum number of DER-encoded bytes a const-evaluated [`oid!`] literal can
+/// produce. Generous enough for every OID arc used in practice, and only
+/// needed to size the fixed-capacity buffer [`oid_encode_padded`] writes
+/// into (const fns can't return a `Vec`, so the macro trims the padding
+/// itself via [`oid_slice`]).
+pub const OID_MAX_ENCODED_LEN: usize = 64;
+
+/// `const fn` counterpart of [`Oid::from`]'s encoding step, used by the
+/// [`oid!`] macro: writes the DER encoding into a fixed-size buffer (padded
+/// with trailing zeroes) and returns it together with the number of bytes
+/// actually used. Malformed arcs are a compile error (`panic!` in a `const
+/// fn` fails the build), matching what `oid!` promises for its literal
+/// arguments.
+pub const fn oid_encode_padded(arcs: &[u64]) -> ([u8; OID_MAX_ENCODED_LEN], usize) {
+    if arcs.len() < 2 {
+        panic!("OID must have at least two arcs");
+    }
+    if arcs[0] > 2 {
+        panic!("first OID arc must be 0, 1 or 2");
+    }
+    if arcs[0] < 2 && arcs[1] >= 40 {
+        panic!("second OID arc must be < 40 when the first arc is 0 or 1");
+    }
+    let mut out = [0u8; OID_MAX_ENCODED_LEN];
+    let mut len = 0usize;
+    // Same multi-byte base-128 encoding as any other arc: `arcs[0]*40+arcs[1]`
+    // only fits one byte when `arcs[0] < 2`, or `arcs[0] == 2` with a small
+    // `arcs[1]` — it is not guaranteed to by the `arcs[0] <= 2` bound alone.
+    let (first_buf, first_n) = base128_digits(arcs[0] * 40 + arcs[1]);
+    let mut i = first_n;
+    while i > 0 {
+        i -= 1;
+        let continuation: u8 = if i != 0 { 0x80 } else { 0x00 };
+        out[len] = first_buf[i] | continuation;
+        len += 1;
+    }
+    let mut idx = 2;
+    while idx < arcs.len() {
+        let (buf, n) = base128_digits(arcs[idx]);
+        let mut i = n;
+        while i > 0 {
+            i -= 1;
+            let continuation: u8 = if i != 0 { 0x80 } else { 0x00 };
+            out[len] = buf[i] | continuation;
+            len += 1;
+        }
+        idx += 1;
+    }
+    (out, len)
+}
+
+/// Trims a padded, const-evaluated buffer from [`oid_encode_padded`] down to
+/// its real length, as a `&'static [u8]` usable in `const` position. Safe:
+/// `len` always comes from the paired `oid_encode_padded` call and is never
+/// larger than `arr.len()`.
+pub const fn oid_slice(arr: &'static [u8; OID_MAX_ENCODED_LEN], len: usize) -> &'static [u8] {
+    unsafe { std::slice::from_raw_parts(arr.as_ptr(), len) }
+}
+
+/// Arbitrary-precision arc support, for registries whose sub-identifiers
+/// exceed 64 bits. Pulls in `num-bigint` only when the `bigint` feature is
+/// enabled; the default `u64`-based [`Oid::from`] path above stays
+/// dependency-free.
+#[cfg(feature = "bigint")]
+mod bigint_support {
+    use super::{Oid, OidBytes, OidParseError};
+    use num_bigint::BigUint;
+
+    impl<'a> Oid<'a> {
+        /// Iterates over every arc of this OID as a `BigUint`, accumulating
+        /// base-128 continuation bytes without ever truncating to a
+        /// machine integer (unlike the plain `u64` arcs [`Oid::from`]
+        /// works with).
+        pub fn iter_bigint(&self) -> impl Iterator<Item = BigUint> + '_ {
+            BigUintArcs {
+                data: self.bytes(),
+                started: false,
+                pending_second: None,
+            }
+        }
+
+        /// Builds an `Oid` at runtime from arbitrary-precision arcs.
+        pub fn from_bigint(arcs: &[BigUint]) -> Result<Oid<'static>, OidParseError> {
+            if arcs.len() < 2 {
+                return Err(OidParseError::TooShort);
+            }
+            let two = BigUint::from(2u32);
+            if arcs[0] > two {
+                return Err(OidParseError::FirstComponentTooLarge);
+            }
+            if arcs[0] < two && arcs[1] >= BigUint::from(40u32) {
+                return Err(OidParseError::SecondComponentTooLarge);
+            }
+            // As with the plain `u64` encoder above, the folded `X*40+Y`
+            // value is only guaranteed to fit one byte when `arcs[0] < 2`;
+            // with `arcs[0] == 2` and a large `arcs[1]` it needs the same
+            // multi-byte base-128 encoding as any other arc.
+            let first = &arcs[0] * 40u32 + &arcs[1];
+            let mut asn1 = Vec::new();
+            push_base128_arc_bigint(&first, &mut asn1);
+            for arc in &arcs[2..] {
+                push_base128_arc_bigint(arc, &mut asn1);
+            }
+            Ok(Oid {
+                asn1: OidBytes::Owned(asn1),
+            })
+        }
+    }
+
+    /// Low 8 bits of a `BigUint`, used to peel off base-128 digits.
+    fn low_byte(n: &BigUint) -> u8 {
+        (n % 256u32).to_bytes_be()[0]
+    }
+
+    /// Appends the base-128 encoding of one arbitrary-precision arc.
+    fn push_base128_arc_bigint(arc: &BigUint, out: &mut Vec<u8>) {
+        let mut digits = Vec::new();
+        let mut n = arc.clone();
+        let zero = BigUint::from(0u32);
+        loop {
+            digits.push(low_byte(&n) & 0x7f);
+            n >>= 7u32;
+            if n == zero {
+                break;
+            }
+        }
+        let mut i = digits.len();
+        while i > 0 {
+            i -= 1;
+            let continuation = if i != 0 { 0x80 } else { 0x00 };
+            out.push(digits[i] | continuation);
+        }
+    }
+
+    /// Iterator driving [`Oid::iter_bigint`]: the first base-128 value folds
+    /// the first two arcs together (`X*40+Y`), so it's decoded like any
+    /// other arc and then split, yielding `X` immediately and stashing `Y`
+    /// in `pending_second` for the following call; every arc after that is
+    /// a standalone base-128 continuation sequence.
+    struct BigUintArcs<'a> {
+        data: &'a [u8],
+        started: bool,
+        pending_second: Option<BigUint>,
+    }
+
+    impl<'a> Iterator for BigUintArcs<'a> {
+        type Item = BigUint;
+
+        fn next(&mut self) -> Option<BigUint> {
+            if let Some(second) = self.pending_second.take() {
+                return Some(second);
+            }
+            if !self.started {
+                self.started = true;
+                let value = take_base128_bigint(&mut self.data)?;
+                let forty = BigUint::from(40u32);
+                let eighty = BigUint::from(80u32);
+                let (x, y) = if value < forty {
+                    (BigUint::from(0u32), value)
+                } else if value < eighty {
+                    (BigUint::from(1u32), value - forty)
+                } else {
+                    (BigUint::from(2u32), value - eighty)
+                };
+                self.pending_second = Some(y);
+                return Some(x);
+            }
+            take_base128_bigint(&mut self.data)
+        }
+    }
+
+    /// Reads one base-128, big-endian, continuation-bit-terminated value off
+    /// the front of `data`, advancing it past the bytes consumed. Shared by
+    /// the folded first subidentifier and every plain trailing arc in
+    /// [`BigUintArcs`], since both are decoded identically. Mirrors
+    /// `super::take_base128_u64` for arbitrary-precision arcs.
+    fn take_base128_bigint(data: &mut &[u8]) -> Option<BigUint> {
+        let mut value = BigUint::from(0u32);
+        loop {
+            let (&byte, rest) = data.split_first()?;
+            *data = rest;
+            value = (value << 7u32) | BigUint::from((byte & 0x7f) as u64);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        Some(value)
+    }
+}
+
+/// Maximum number of arcs a const-evaluated [`oid!`] literal can have.
+pub const OID_MAX_ARCS: usize = 32;
+
+/// `const fn` companion to [`oid!`]: rustc's lexer folds a dotted sequence
+/// like `1.2.840.113549.1.1.1` into a handful of float/int literal tokens
+/// (`1.2`, `840.113549`, `1.1`, `1`, each keeping its own embedded dot)
+/// rather than seven separate integers, so the macro can't match arcs one
+/// literal at a time. Instead it stringifies the whole token tree and this
+/// function re-splits that string on every non-digit byte, which recovers
+/// the original arcs regardless of how the lexer happened to group them.
+pub const fn parse_dotted_arcs(s: &str) -> ([u64; OID_MAX_ARCS], usize) {
+    let bytes = s.as_bytes();
+    let mut arcs = [0u64; OID_MAX_ARCS];
+    let mut count = 0usize;
+    let mut cur: u64 = 0;
+    let mut in_digits = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            cur = cur * 10 + (bytes[i] - b'0') as u64;
+            in_digits = true;
+        } else if in_digits {
+            if count >= OID_MAX_ARCS {
+                panic!("too many OID arcs");
+            }
+            arcs[count] = cur;
+            count += 1;
+            cur = 0;
+            in_digits = false;
+        }
+        i += 1;
+    }
+    if in_digits {
+        if count >= OID_MAX_ARCS {
+            panic!("too many OID arcs");
+        }
+        arcs[count] = cur;
+        count += 1;
+    }
+    (arcs, count)
+}
+
+/// Trims a padded, const-evaluated arc buffer from [`parse_dotted_arcs`]
+/// down to its real length, as a `&'static [u64]` usable in `const`
+/// position. Safe: `len` always comes from the paired `parse_dotted_arcs`
+/// call and is never larger than `arr.len()`.
+pub const fn u64_slice(arr: &'static [u64; OID_MAX_ARCS], len: usize) -> &'static [u64] {
+    unsafe { std::slice::from_raw_parts(arr.as_ptr(), len) }
+}
+
+/// Builds `Oid` (or raw DER byte) constants usable in `const` position, and
+/// therefore directly as `match` patterns, e.g.:
+/// ```ignore
+/// const OID_RSA: Oid = oid!(const 1.2.840.113549.1.1.1);
+/// match oid { OID_RSA => ..., _ => ... }
+/// ```
+/// `oid!(raw 1.2...)` expands to a `[u8]` place expression holding the exact
+/// DER encoding (callers take a reference to it); the bare `oid!(1.2...)`
+/// and `oid!(const 1.2...)` forms both wrap that in an `Oid<'static>`
+/// instead and are equivalent — `const` is accepted as an explicit spelling
+/// for use in `const` declarations, since that's the position this is meant
+/// for.
+#[macro_export]
+macro_rules! oid {
+    (raw $($arc:tt)*) => {
+        *{
+            const ARCS_STR: &str = stringify!($($arc)*);
+            const PARSED: ([u64; $crate::oid::OID_MAX_ARCS], usize) =
+                $crate::oid::parse_dotted_arcs(ARCS_STR);
+            const ARCS_ARR: [u64; $crate::oid::OID_MAX_ARCS] = PARSED.0;
+            const ARCS: &[u64] = $crate::oid::u64_slice(&ARCS_ARR, PARSED.1);
+            const ENCODED: ([u8; $crate::oid::OID_MAX_ENCODED_LEN], usize) =
+                $crate::oid::oid_encode_padded(ARCS);
+            const ARR: [u8; $crate::oid::OID_MAX_ENCODED_LEN] = ENCODED.0;
+            $crate::oid::oid_slice(&ARR, ENCODED.1)
+        }
+    };
+    (const $($arc:tt)*) => {
+        $crate::oid::Oid::new(&$crate::oid!(raw $($arc)*))
+    };
+    ($($arc:tt)*) => {
+        $crate::oid::Oid::new(&$crate::oid!(raw $($arc)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oid_first_subidentifier_no_truncation() {
+        // arcs[0] == 2 with a large arcs[1] folds into a value that needs
+        // more than one base-128 byte; regression test for a bug where it
+        // was cast straight to `u8`, silently truncating and colliding with
+        // unrelated OIDs (this used to encode identically to the second
+        // OID below).
+        let a = Oid::from(&[2, 181, 422435, 588674, 105007]).unwrap();
+        let b = Oid::from(&[0, 5, 422435, 588674, 105007]).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(a.to_string(), "2.181.422435.588674.105007");
+        assert_eq!(b.to_string(), "0.5.422435.588674.105007");
+    }
+
+    #[test]
+    fn test_oid_const_match_pattern() {
+        // Oid's PartialEq/Eq must be structural for this to even compile:
+        // a `const Oid` used directly as a match arm, rather than via
+        // `.bytes()` first.
+        const OID_RSA: Oid = oid!(const 1.2.840.113549.1.1.1);
+        let oid = Oid::from(&[1, 2, 840, 113_549, 1, 1, 1]).unwrap();
+        let matched = match oid {
+            OID_RSA => true,
+            _ => false,
+        };
+        assert!(matched);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_oid_bigint_arc_roundtrip() {
+        use num_bigint::BigUint;
+
+        let arcs: Vec<BigUint> = vec![
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+            BigUint::from(840u32),
+            // larger than a u64 can hold, to exercise the arbitrary-precision path
+            BigUint::parse_bytes(b"123456789012345678901234567890", 10).unwrap(),
+        ];
+        let oid = Oid::from_bigint(&arcs).unwrap();
+        let decoded: Vec<BigUint> = oid.iter_bigint().collect();
+        assert_eq!(decoded, arcs);
+    }
+
+    #[test]
+    fn test_oid_from_str_display_roundtrip() {
+        let oid: Oid = "1.2.840.113549.1.1.1".parse().unwrap();
+        assert_eq!(oid.to_string(), "1.2.840.113549.1.1.1");
+    }
+
+    #[test]
+    fn test_oid_from_str_errors() {
+        assert_eq!("".parse::<Oid>().unwrap_err(), OidParseError::Empty);
+        assert_eq!("1".parse::<Oid>().unwrap_err(), OidParseError::TooShort);
+        assert_eq!(
+            "3.1".parse::<Oid>().unwrap_err(),
+            OidParseError::FirstComponentTooLarge
+        );
+        assert_eq!(
+            "1.40".parse::<Oid>().unwrap_err(),
+            OidParseError::SecondComponentTooLarge
+        );
+        assert_eq!(
+            "1.x".parse::<Oid>().unwrap_err(),
+            OidParseError::InvalidDigit(1)
+        );
+        assert_eq!(
+            "1.2.99999999999999999999".parse::<Oid>().unwrap_err(),
+            OidParseError::IntegerOverflow(2)
+        );
+    }
+}
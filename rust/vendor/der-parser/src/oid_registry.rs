@@ -0,0 +1,174 @@
+//! Built-in OID name/category registry.
+//!
+//! Maps well-known OIDs to a short name, a description, and the category
+//! they belong to (PKCS#1, X9.62/EC, NIST algorithms, KDF...), so callers
+//! don't have to hand-maintain their own oid -> name tables. Disabled by
+//! default; enable the `registry` feature, plus whichever category
+//! features you need, to compile the corresponding tables in.
+
+use crate::oid::Oid;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// The category a registry entry belongs to, gated behind its own Cargo
+/// feature so embedded users only pay for the tables they enable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OidCategory {
+    #[cfg(feature = "pkcs1")]
+    Pkcs1,
+    #[cfg(feature = "x962")]
+    X962,
+    #[cfg(feature = "nist_algs")]
+    NistAlgs,
+    #[cfg(feature = "kdf")]
+    Kdf,
+}
+
+/// One entry in an [`OidRegistry`]: the canonical short name, a longer
+/// description, and the category it was loaded under.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OidEntry {
+    pub sn: &'static str,
+    pub description: &'static str,
+    pub category: OidCategory,
+}
+
+impl OidEntry {
+    const fn new(sn: &'static str, description: &'static str, category: OidCategory) -> Self {
+        OidEntry {
+            sn,
+            description,
+            category,
+        }
+    }
+}
+
+/// A table of known OIDs, keyed by their DER encoding.
+#[derive(Debug, Default)]
+pub struct OidRegistry {
+    map: HashMap<Vec<u8>, OidEntry>,
+}
+
+impl OidRegistry {
+    pub fn new() -> Self {
+        OidRegistry {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Registers an entry, returning the previous one (if any) that had the
+    /// same encoding.
+    pub fn insert(&mut self, oid: &Oid, entry: OidEntry) -> Option<OidEntry> {
+        self.map.insert(oid.bytes().to_vec(), entry)
+    }
+
+    /// Looks up an OID's registry entry, if known.
+    pub fn get(&self, oid: &Oid) -> Option<&OidEntry> {
+        self.map.get(oid.bytes())
+    }
+
+    /// Builds a registry populated with every category feature compiled in.
+    pub fn with_all_known() -> Self {
+        let mut reg = OidRegistry::new();
+        #[cfg(feature = "pkcs1")]
+        load_pkcs1(&mut reg);
+        #[cfg(feature = "x962")]
+        load_x962(&mut reg);
+        #[cfg(feature = "nist_algs")]
+        load_nist_algs(&mut reg);
+        #[cfg(feature = "kdf")]
+        load_kdf(&mut reg);
+        reg
+    }
+}
+
+#[cfg(feature = "pkcs1")]
+fn load_pkcs1(reg: &mut OidRegistry) {
+    use crate::oid;
+    reg.insert(
+        &oid!(1.2.840.113549.1.1.1),
+        OidEntry::new("rsaEncryption", "PKCS #1 RSA Encryption", OidCategory::Pkcs1),
+    );
+    reg.insert(
+        &oid!(1.2.840.113549.1.1.11),
+        OidEntry::new(
+            "sha256WithRSAEncryption",
+            "PKCS #1 SHA-256 with RSA Encryption",
+            OidCategory::Pkcs1,
+        ),
+    );
+}
+
+#[cfg(feature = "x962")]
+fn load_x962(reg: &mut OidRegistry) {
+    use crate::oid;
+    reg.insert(
+        &oid!(1.2.840.10045.2.1),
+        OidEntry::new(
+            "id-ecPublicKey",
+            "X9.62 Elliptic Curve Public Key",
+            OidCategory::X962,
+        ),
+    );
+    reg.insert(
+        &oid!(1.2.840.10045.3.1.7),
+        OidEntry::new("prime256v1", "X9.62 NIST P-256 Curve", OidCategory::X962),
+    );
+}
+
+#[cfg(feature = "nist_algs")]
+fn load_nist_algs(reg: &mut OidRegistry) {
+    use crate::oid;
+    reg.insert(
+        &oid!(2.16.840.1.101.3.4.2.1),
+        OidEntry::new("sha256", "NIST Algorithm SHA-256", OidCategory::NistAlgs),
+    );
+    reg.insert(
+        &oid!(2.16.840.1.101.3.4.2.3),
+        OidEntry::new("sha512", "NIST Algorithm SHA-512", OidCategory::NistAlgs),
+    );
+}
+
+#[cfg(feature = "kdf")]
+fn load_kdf(reg: &mut OidRegistry) {
+    use crate::oid;
+    reg.insert(
+        &oid!(1.2.840.113549.1.5.12),
+        OidEntry::new(
+            "pbkdf2",
+            "PKCS #5 PBKDF2 Key Derivation Function",
+            OidCategory::Kdf,
+        ),
+    );
+}
+
+static GLOBAL_REGISTRY: OnceLock<OidRegistry> = OnceLock::new();
+
+/// Returns the default global registry, built once (on first use) from
+/// every category feature compiled in.
+pub fn oid_registry() -> &'static OidRegistry {
+    GLOBAL_REGISTRY.get_or_init(OidRegistry::with_all_known)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oid;
+
+    #[test]
+    fn test_oid_registry_unknown_oid_returns_none() {
+        let reg = OidRegistry::new();
+        let unknown = oid!(1.2.3.4);
+        assert!(reg.get(&unknown).is_none());
+    }
+
+    #[cfg(feature = "pkcs1")]
+    #[test]
+    fn test_oid_registry_lookup_rsa_encryption() {
+        let reg = oid_registry();
+        let rsa = oid!(1.2.840.113549.1.1.1);
+        let entry = reg.get(&rsa).expect("rsaEncryption should be registered");
+        assert_eq!(entry.sn, "rsaEncryption");
+        assert_eq!(entry.category, OidCategory::Pkcs1);
+    }
+}
@@ -21,3 +21,25 @@ fn test_compare_oid() {
     let oid = Oid::from(&[1, 2, 840, 113_549, 1, 1, 1]).unwrap();
     assert!(compare_oid(&oid));
 }
+
+// `Oid` derives `PartialEq`/`Eq` structurally over its internal bytes, so a
+// `const Oid` can be used directly as a `match` pattern, without going
+// through `.bytes()` first as `compare_oid` above does.
+const OID_RSA: Oid = oid!(const 1.2.840.113549.1.1.1);
+const OID_EC: Oid = oid!(const 1.2.840.10045.2.1);
+
+fn compare_oid_typed(oid: Oid) -> bool {
+    match oid {
+        OID_RSA => true,
+        OID_EC => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test_compare_oid_typed_const() {
+    let oid = Oid::from(&[1, 2, 840, 113_549, 1, 1, 1]).unwrap();
+    assert!(compare_oid_typed(oid));
+    let oid = Oid::from(&[1, 2, 3]).unwrap();
+    assert!(!compare_oid_typed(oid));
+}